@@ -0,0 +1,24 @@
+/// Errors that can occur while parsing or building a MODBUS ADU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModbusError {
+    /// The buffer didn't contain enough bytes to complete the operation.
+    ///
+    /// For protocols that validate a trailing checksum (MODBUS RTU's CRC, MODBUS ASCII's LRC),
+    /// this is also returned when the checksum doesn't match, since there's no way to distinguish
+    /// "truncated frame" from "corrupt frame" without a length field to check against.
+    NotEnoughData,
+
+    /// The PDU was shorter than the function code requires.
+    ///
+    /// Every function code has a minimum number of data bytes it needs to carry (a start address
+    /// and quantity, a single address/value pair, and so on), which also depends on whether the
+    /// PDU is a request or a response; see [`crate::pdu::decode_pdu`].
+    MalformedPdu,
+
+    /// The PDU is a MODBUS exception response rather than a normal response.
+    ///
+    /// A server signals an exception by setting the high bit of the echoed function code and
+    /// following it with a one-byte exception code; see [`crate::pdu::is_exception`] and
+    /// [`crate::pdu::ExceptionCode`].
+    Exception(crate::pdu::ExceptionCode),
+}
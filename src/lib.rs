@@ -0,0 +1,11 @@
+//! A minimal implementation of the MODBUS protocol family.
+//!
+//! This crate handles the framing layer of MODBUS: splitting a stream of bytes into Application
+//! Data Units (ADUs), validating them, and extracting the Protocol Data Unit (PDU) they carry. See
+//! [`protocols::ModbusProtocol`] for the entry point.
+
+mod error;
+pub mod pdu;
+pub mod protocols;
+
+pub use error::ModbusError;
@@ -0,0 +1,236 @@
+//! Decoding of the MODBUS Protocol Data Unit (PDU), the function code and payload that
+//! [`protocols::ModbusProtocol::pdu_body`](crate::protocols::ModbusProtocol::pdu_body) extracts
+//! from an ADU.
+
+use crate::ModbusError;
+
+/// A MODBUS function code.
+///
+/// This only covers the function codes this crate knows the minimum data size for; see
+/// [`decode_pdu`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FunctionCode {
+    ReadCoils,
+    ReadDiscreteInputs,
+    ReadHoldingRegisters,
+    ReadInputRegisters,
+    WriteSingleCoil,
+    WriteSingleRegister,
+    ReadExceptionStatus,
+}
+
+impl FunctionCode {
+    fn from_u8(code: u8) -> Option<Self> {
+        match code {
+            0x01 => Some(Self::ReadCoils),
+            0x02 => Some(Self::ReadDiscreteInputs),
+            0x03 => Some(Self::ReadHoldingRegisters),
+            0x04 => Some(Self::ReadInputRegisters),
+            0x05 => Some(Self::WriteSingleCoil),
+            0x06 => Some(Self::WriteSingleRegister),
+            0x07 => Some(Self::ReadExceptionStatus),
+            _ => None,
+        }
+    }
+
+    // The fewest data bytes (not counting the function code itself) that a PDU using this
+    // function code can carry, in the given direction. Modeled on Qt Modbus's
+    // `QModbusRequest::minimumDataSize`.
+    fn minimum_data_size(self, direction: Direction) -> usize {
+        match (self, direction) {
+            (
+                Self::ReadCoils
+                | Self::ReadDiscreteInputs
+                | Self::ReadHoldingRegisters
+                | Self::ReadInputRegisters,
+                Direction::Request,
+            ) => 4, // starting address, quantity
+            (
+                Self::ReadCoils
+                | Self::ReadDiscreteInputs
+                | Self::ReadHoldingRegisters
+                | Self::ReadInputRegisters,
+                Direction::Response,
+            ) => 2, // byte count, at least one byte of data
+            (Self::WriteSingleCoil | Self::WriteSingleRegister, _) => 4, // address, value
+            (Self::ReadExceptionStatus, Direction::Request) => 0,
+            (Self::ReadExceptionStatus, Direction::Response) => 1, // status byte
+        }
+    }
+}
+
+/// Which side of the request/response exchange a PDU represents.
+///
+/// The minimum data size for a function code depends on this: a `ReadHoldingRegisters` request is
+/// a fixed 4 bytes (address and quantity), while its response carries a variable-length register
+/// dump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Request,
+    Response,
+}
+
+/// A decoded PDU: its function code, and the data that follows it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pdu<'a> {
+    pub function_code: FunctionCode,
+    pub data: &'a [u8],
+}
+
+// High bit a server sets on the echoed function code to signal an exception response
+const EXCEPTION_BIT: u8 = 0x80;
+
+/// Returns `true` if `pdu` starts with a function code that has its high bit set, which marks a
+/// MODBUS exception response (such a function code is followed by a one-byte exception code).
+///
+/// Only the first byte is inspected; whether the exception code byte is present is not checked.
+pub fn is_exception(pdu: &[u8]) -> bool {
+    pdu.first().is_some_and(|&function_byte| function_byte & EXCEPTION_BIT != 0)
+}
+
+/// A MODBUS exception code, returned by a server in place of a normal response when it can't
+/// service a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExceptionCode {
+    IllegalFunction,
+    IllegalDataAddress,
+    IllegalDataValue,
+    ServerDeviceFailure,
+    Acknowledge,
+    ServerDeviceBusy,
+    NegativeAcknowledge,
+    MemoryParityError,
+    GatewayPathUnavailable,
+    GatewayTargetDeviceFailedToRespond,
+}
+
+impl ExceptionCode {
+    fn from_u8(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(Self::IllegalFunction),
+            2 => Some(Self::IllegalDataAddress),
+            3 => Some(Self::IllegalDataValue),
+            4 => Some(Self::ServerDeviceFailure),
+            5 => Some(Self::Acknowledge),
+            6 => Some(Self::ServerDeviceBusy),
+            7 => Some(Self::NegativeAcknowledge),
+            8 => Some(Self::MemoryParityError),
+            10 => Some(Self::GatewayPathUnavailable),
+            11 => Some(Self::GatewayTargetDeviceFailedToRespond),
+            _ => None,
+        }
+    }
+}
+
+/// Decodes a PDU, checking that it carries at least as much data as `function_code` requires for
+/// `direction`.
+///
+/// `pdu` is the PDU as returned by
+/// [`ModbusProtocol::pdu_body`](crate::protocols::ModbusProtocol::pdu_body): the function code
+/// byte followed by its data. If `pdu` is an exception response (see [`is_exception`]), this
+/// returns [`ModbusError::Exception`] instead of decoding it as a normal PDU; an exception
+/// response whose exception code byte is missing or not a known [`ExceptionCode`] returns
+/// [`ModbusError::MalformedPdu`].
+pub fn decode_pdu(pdu: &[u8], direction: Direction) -> Result<Pdu<'_>, ModbusError> {
+    use ModbusError::{Exception, MalformedPdu};
+
+    if is_exception(pdu) {
+        let &exception_byte = pdu.get(1).ok_or(MalformedPdu)?;
+        return Err(Exception(
+            ExceptionCode::from_u8(exception_byte).ok_or(MalformedPdu)?,
+        ));
+    }
+
+    let (&function_byte, data) = pdu.split_first().ok_or(MalformedPdu)?;
+    let function_code = FunctionCode::from_u8(function_byte).ok_or(MalformedPdu)?;
+
+    if data.len() < function_code.minimum_data_size(direction) {
+        return Err(MalformedPdu);
+    }
+
+    Ok(Pdu {
+        function_code,
+        data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_holding_registers_request_needs_four_data_bytes() {
+        let short = [0x03, 0x00, 0x00, 0x00];
+        assert_eq!(
+            decode_pdu(&short, Direction::Request),
+            Err(ModbusError::MalformedPdu)
+        );
+
+        let full = [0x03, 0x00, 0x00, 0x00, 0x02];
+        let pdu = decode_pdu(&full, Direction::Request).unwrap();
+        assert_eq!(pdu.function_code, FunctionCode::ReadHoldingRegisters);
+        assert_eq!(pdu.data, &full[1..]);
+    }
+
+    #[test]
+    fn read_exception_status_request_needs_no_data_bytes() {
+        let pdu = decode_pdu(&[0x07], Direction::Request).unwrap();
+        assert_eq!(pdu.function_code, FunctionCode::ReadExceptionStatus);
+        assert!(pdu.data.is_empty());
+    }
+
+    #[test]
+    fn write_single_coil_needs_four_data_bytes_in_either_direction() {
+        let short = [0x05, 0x00, 0x00];
+        assert_eq!(
+            decode_pdu(&short, Direction::Request),
+            Err(ModbusError::MalformedPdu)
+        );
+        assert_eq!(
+            decode_pdu(&short, Direction::Response),
+            Err(ModbusError::MalformedPdu)
+        );
+    }
+
+    #[test]
+    fn unknown_function_code_is_malformed() {
+        assert_eq!(
+            decode_pdu(&[0x42, 0x00], Direction::Request),
+            Err(ModbusError::MalformedPdu)
+        );
+    }
+
+    #[test]
+    fn empty_pdu_is_malformed() {
+        assert_eq!(
+            decode_pdu(&[], Direction::Request),
+            Err(ModbusError::MalformedPdu)
+        );
+    }
+
+    #[test]
+    fn exception_response_decodes_to_exception_code() {
+        let exception_pdu = [0x03 | 0x80, 0x02];
+
+        assert!(is_exception(&exception_pdu));
+        assert_eq!(
+            decode_pdu(&exception_pdu, Direction::Response),
+            Err(ModbusError::Exception(ExceptionCode::IllegalDataAddress))
+        );
+    }
+
+    #[test]
+    fn non_exception_response_is_not_flagged() {
+        assert!(!is_exception(&[0x03, 0x02, 0x00, 0x01]));
+    }
+
+    #[test]
+    fn exception_response_missing_exception_byte_is_malformed() {
+        assert_eq!(
+            decode_pdu(&[0x03 | 0x80], Direction::Response),
+            Err(ModbusError::MalformedPdu)
+        );
+    }
+}
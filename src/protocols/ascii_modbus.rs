@@ -0,0 +1,307 @@
+use super::ModbusProtocol;
+use crate::ModbusError;
+
+/// ASCII MODBUS protocol implementation
+///
+/// ASCII MODBUS is meant to be read by a human watching a serial line, or to pass cleanly through
+/// gateways that aren't transparent to arbitrary bytes. Every byte of the ADU is encoded as two
+/// uppercase hex characters, the whole frame is bracketed by a leading `:` (0x3A) and a trailing
+/// CR/LF (0x0D 0x0A), and the last encoded byte is a Longitudinal Redundancy Check (LRC) rather
+/// than a CRC.
+///
+/// Visually, the decoded contents of an ASCII MODBUS ADU look the same as an
+/// [`RtuModbus`](super::RtuModbus) ADU, minus one checksum byte:
+///
+/// | Offset (decoded) | Field |
+/// | --- | --- |
+/// | 0 | Unit ID |
+/// | 1 | Function Code |
+/// | 2... | Continuing PDU Data |
+/// | len-1 | LRC |
+///
+/// Because decoding the hex digits moves bytes around, [`pdu_body`](ModbusProtocol::pdu_body)
+/// can't hand back a slice of the original buffer the way [`TcpModbus`](super::TcpModbus) and
+/// [`RtuModbus`](super::RtuModbus) do; it returns an owned `Vec<u8>` instead (see
+/// [`ModbusProtocol::PduBody`]).
+pub struct AsciiModbus;
+
+const START: u8 = b':';
+const CR: u8 = b'\r';
+const LF: u8 = b'\n';
+
+// Largest decoded frame (unit ID + PDU + LRC) this implementation will accept
+const DECODED_MAX_LENGTH: usize = 256;
+
+// Smallest decoded frame: unit ID, function code, LRC
+const DECODED_MIN_LENGTH: usize = 3;
+
+/// ASCII MODBUS header data
+#[derive(Debug, Clone)]
+pub struct AsciiModbusHeader {
+    pub unit_id: u8,
+}
+
+impl AsciiModbus {
+    fn hex_nibble(c: u8) -> Option<u8> {
+        match c {
+            b'0'..=b'9' => Some(c - b'0'),
+            b'A'..=b'F' => Some(c - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    fn decode_hex_byte(hi: u8, lo: u8) -> Option<u8> {
+        Some((Self::hex_nibble(hi)? << 4) | Self::hex_nibble(lo)?)
+    }
+
+    fn decode_hex_into(hex: &[u8], out: &mut [u8]) -> Result<(), ModbusError> {
+        use ModbusError::NotEnoughData;
+
+        for (pair, out_byte) in hex.chunks_exact(2).zip(out.iter_mut()) {
+            *out_byte = Self::decode_hex_byte(pair[0], pair[1]).ok_or(NotEnoughData)?;
+        }
+
+        Ok(())
+    }
+
+    fn lrc(payload: &[u8]) -> u8 {
+        let sum = payload.iter().fold(0u8, |acc, &b| acc.wrapping_add(b));
+
+        (!sum).wrapping_add(1)
+    }
+
+    // Encodes `byte` as two uppercase hex ASCII characters
+    fn encode_hex_byte(byte: u8) -> [u8; 2] {
+        const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+        [DIGITS[(byte >> 4) as usize], DIGITS[(byte & 0xF) as usize]]
+    }
+
+    // Requires `data` to begin with the start-of-frame `:`. Returns the hex digits between the
+    // delimiters and the total length of the encoded frame (including the leading `:` and
+    // trailing CRLF)
+    fn find_frame(data: &[u8]) -> Result<(&[u8], usize), ModbusError> {
+        use ModbusError::NotEnoughData;
+
+        if data.first() != Some(&START) {
+            return Err(NotEnoughData);
+        }
+
+        let crlf_offset = data[1..]
+            .windows(2)
+            .position(|w| w == [CR, LF])
+            .ok_or(NotEnoughData)?;
+
+        let hex = &data[1..1 + crlf_offset];
+
+        if hex.is_empty() || !hex.len().is_multiple_of(2) {
+            return Err(NotEnoughData);
+        }
+
+        Ok((hex, 1 + crlf_offset + 2))
+    }
+}
+
+impl ModbusProtocol for AsciiModbus {
+    // 1 start byte + 2 hex characters per decoded byte + 2 CRLF bytes
+    const ADU_MAX_LENGTH: usize = 1 + 2 * DECODED_MAX_LENGTH + 2;
+
+    type Header = AsciiModbusHeader;
+    type PduBody<'a> = Vec<u8>;
+
+    fn adu_length(data: &[u8]) -> Result<usize, ModbusError> {
+        Self::find_frame(data).map(|(_, length)| length)
+    }
+
+    fn adu_header(data: &[u8]) -> Result<Self::Header, ModbusError> {
+        use ModbusError::NotEnoughData;
+
+        let (hex, _) = Self::find_frame(data)?;
+        let &[hi, lo, ..] = hex else {
+            return Err(NotEnoughData);
+        };
+
+        Ok(Self::Header {
+            unit_id: Self::decode_hex_byte(hi, lo).ok_or(NotEnoughData)?,
+        })
+    }
+
+    fn adu_check(data: &[u8]) -> Result<(), ModbusError> {
+        use ModbusError::NotEnoughData;
+
+        let (hex, _) = Self::find_frame(data)?;
+        let decoded_len = hex.len() / 2;
+
+        if !(DECODED_MIN_LENGTH..=DECODED_MAX_LENGTH).contains(&decoded_len) {
+            return Err(NotEnoughData);
+        }
+
+        let mut decoded = [0u8; DECODED_MAX_LENGTH];
+        let decoded = &mut decoded[..decoded_len];
+        Self::decode_hex_into(hex, decoded)?;
+
+        let (payload, lrc_byte) = decoded.split_at(decoded_len - 1);
+
+        if Self::lrc(payload) == lrc_byte[0] {
+            Ok(())
+        } else {
+            Err(NotEnoughData)
+        }
+    }
+
+    fn pdu_body(data: &[u8]) -> Result<Self::PduBody<'_>, ModbusError> {
+        Self::adu_check(data)?;
+
+        // We just checked the LRC (and therefore the length and hex encoding) in adu_check, so
+        // this won't panic
+        let (hex, _) = Self::find_frame(data)?;
+        let decoded_len = hex.len() / 2;
+        let mut decoded = vec![0u8; decoded_len];
+        Self::decode_hex_into(hex, &mut decoded)?;
+
+        // PDU is function code onward: skip the unit ID, drop the trailing LRC
+        decoded.remove(0);
+        decoded.pop();
+
+        Ok(decoded)
+    }
+
+    fn build_adu(
+        header: &Self::Header,
+        pdu_body: &[u8],
+        out: &mut [u8],
+    ) -> Result<usize, ModbusError> {
+        let decoded_len = 1 + pdu_body.len() + 1; // unit ID, PDU, LRC
+        let adu_length = 1 + 2 * decoded_len + 2; // ':', hex digits, CRLF
+        let out = out.get_mut(..adu_length).ok_or(ModbusError::NotEnoughData)?;
+
+        let sum = pdu_body
+            .iter()
+            .fold(header.unit_id, |acc, &byte| acc.wrapping_add(byte));
+        let lrc = (!sum).wrapping_add(1);
+
+        out[0] = START;
+
+        let mut offset = 1;
+        for &byte in core::iter::once(&header.unit_id)
+            .chain(pdu_body)
+            .chain(core::iter::once(&lrc))
+        {
+            out[offset..offset + 2].copy_from_slice(&Self::encode_hex_byte(byte));
+            offset += 2;
+        }
+
+        out[offset] = CR;
+        out[offset + 1] = LF;
+
+        Ok(adu_length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_adu_round_trips_through_adu_check_and_pdu_body() {
+        let header = AsciiModbusHeader { unit_id: 0x11 };
+        let pdu_body = [0x03, 0x00, 0x00, 0x00, 0x02];
+        let mut buf = [0u8; AsciiModbus::ADU_MAX_LENGTH];
+
+        let written = AsciiModbus::build_adu(&header, &pdu_body, &mut buf).unwrap();
+        let adu = &buf[..written];
+
+        AsciiModbus::adu_check(adu).unwrap();
+        assert_eq!(AsciiModbus::pdu_body(adu).unwrap(), pdu_body);
+        assert_eq!(AsciiModbus::adu_header(adu).unwrap().unit_id, header.unit_id);
+    }
+
+    // Unit 0x11, function 0x03 (ReadHoldingRegisters), data 0x00 0x6B 0x00 0x03, LRC 0x7E
+    // (0x11 + 0x03 + 0x00 + 0x6B + 0x00 + 0x03 == 0x82, whose two's complement is 0x7E)
+    const VALID_FRAME: &[u8] = b":1103006B00037E\r\n";
+
+    #[test]
+    fn lrc_matches_hand_computed_checksum() {
+        let payload = [0x11, 0x03, 0x00, 0x6B, 0x00, 0x03];
+        assert_eq!(AsciiModbus::lrc(&payload), 0x7E);
+    }
+
+    #[test]
+    fn adu_check_accepts_a_valid_frame() {
+        assert_eq!(AsciiModbus::adu_check(VALID_FRAME), Ok(()));
+    }
+
+    #[test]
+    fn adu_check_rejects_a_mismatched_lrc() {
+        let mut frame = VALID_FRAME.to_vec();
+        // Corrupt the first hex digit of the LRC (`7E` -> `8E`)
+        let lrc_digit = frame.len() - 4;
+        frame[lrc_digit] = b'8';
+
+        assert_eq!(
+            AsciiModbus::adu_check(&frame),
+            Err(ModbusError::NotEnoughData)
+        );
+    }
+
+    #[test]
+    fn find_frame_rejects_a_missing_start_byte() {
+        let frame = b"1103006B00037E\r\n";
+        assert_eq!(
+            AsciiModbus::adu_check(frame),
+            Err(ModbusError::NotEnoughData)
+        );
+    }
+
+    #[test]
+    fn find_frame_rejects_a_missing_crlf() {
+        let frame = b":1103006B00037E";
+        assert_eq!(
+            AsciiModbus::adu_check(frame),
+            Err(ModbusError::NotEnoughData)
+        );
+    }
+
+    #[test]
+    fn find_frame_rejects_odd_length_hex() {
+        let frame = b":1103006B00037E0\r\n";
+        assert_eq!(
+            AsciiModbus::adu_check(frame),
+            Err(ModbusError::NotEnoughData)
+        );
+    }
+
+    #[test]
+    fn find_frame_rejects_non_hex_characters() {
+        let frame = b":11030G6B00037E\r\n";
+        assert_eq!(
+            AsciiModbus::adu_check(frame),
+            Err(ModbusError::NotEnoughData)
+        );
+    }
+
+    #[test]
+    fn next_adu_splits_two_concatenated_frames_without_consuming_extra_bytes() {
+        let header = AsciiModbusHeader { unit_id: 0x11 };
+        let first_pdu = [0x03, 0x00, 0x00, 0x00, 0x02];
+        let second_pdu = [0x10, 0x00, 0x01];
+
+        let mut first_buf = [0u8; AsciiModbus::ADU_MAX_LENGTH];
+        let first_len = AsciiModbus::build_adu(&header, &first_pdu, &mut first_buf).unwrap();
+
+        let mut second_buf = [0u8; AsciiModbus::ADU_MAX_LENGTH];
+        let second_len = AsciiModbus::build_adu(&header, &second_pdu, &mut second_buf).unwrap();
+
+        let mut combined = Vec::new();
+        combined.extend_from_slice(&first_buf[..first_len]);
+        combined.extend_from_slice(&second_buf[..second_len]);
+
+        let (first_adu, rest) = AsciiModbus::next_adu(&combined).unwrap();
+        assert_eq!(first_adu, &first_buf[..first_len]);
+        assert_eq!(rest, &second_buf[..second_len]);
+
+        let (second_adu, rest) = AsciiModbus::next_adu(rest).unwrap();
+        assert_eq!(second_adu, &second_buf[..second_len]);
+        assert!(rest.is_empty());
+    }
+}
@@ -0,0 +1,91 @@
+mod ascii_modbus;
+mod rtu_modbus;
+mod tcp_modbus;
+
+pub use ascii_modbus::{AsciiModbus, AsciiModbusHeader};
+pub use rtu_modbus::{RtuModbus, RtuModbusHeader};
+pub use tcp_modbus::{TcpModbus, TcpModbusHeader};
+
+use core::ops::Deref;
+
+use crate::ModbusError;
+
+/// A MODBUS protocol variant, responsible for framing ADUs and extracting the PDU they carry.
+///
+/// Each variant (TCP, RTU, ASCII, ...) has its own framing rules, but they all boil down to the
+/// same three operations: figuring out how long the ADU is, reading whatever header precedes the
+/// PDU, and validating the frame (via a length field, a checksum, or both).
+pub trait ModbusProtocol {
+    /// The largest possible ADU for this protocol, in bytes.
+    const ADU_MAX_LENGTH: usize;
+
+    /// The header fields that precede the PDU in this protocol's ADU.
+    type Header;
+
+    /// The PDU bytes returned by [`pdu_body`](Self::pdu_body).
+    ///
+    /// Most protocols carry the PDU verbatim within the ADU, so this is just a borrowed slice of
+    /// the input. MODBUS ASCII re-encodes every byte as hex, so its decoded PDU doesn't occupy a
+    /// contiguous range of the original buffer and this is an owned buffer instead.
+    type PduBody<'a>: Deref<Target = [u8]>
+    where
+        Self: 'a;
+
+    /// Returns the total length of the ADU starting at `data`, in bytes.
+    ///
+    /// This only inspects as much of `data` as it needs to determine the length; it does not
+    /// require the full ADU to be present, except where the protocol has no other way to find the
+    /// length (see [`RtuModbus`]).
+    fn adu_length(data: &[u8]) -> Result<usize, ModbusError>;
+
+    /// Parses the header fields that precede the PDU.
+    fn adu_header(data: &[u8]) -> Result<Self::Header, ModbusError>;
+
+    /// Confirms that `data` holds a complete, valid ADU.
+    ///
+    /// This checks whatever the protocol provides: a length field, a checksum, or both.
+    fn adu_check(data: &[u8]) -> Result<(), ModbusError>;
+
+    /// Returns the PDU carried by this ADU, starting with the function code.
+    fn pdu_body(data: &[u8]) -> Result<Self::PduBody<'_>, ModbusError>;
+
+    /// Serializes `header` and `pdu_body` into a complete ADU, writing it to `out` and returning
+    /// the number of bytes written.
+    ///
+    /// This writes into a caller-supplied buffer rather than allocating, so it works the same way
+    /// on protocols with fixed framing overhead (MODBUS TCP, RTU) and protocols that expand the
+    /// PDU on the wire (MODBUS ASCII's hex encoding). Returns
+    /// [`ModbusError::NotEnoughData`] if `out` isn't large enough to hold the serialized ADU.
+    fn build_adu(
+        header: &Self::Header,
+        pdu_body: &[u8],
+        out: &mut [u8],
+    ) -> Result<usize, ModbusError>;
+
+    /// Carves the next complete ADU off the front of `data`, returning `(this_adu, remaining)`.
+    ///
+    /// This is the primitive a non-blocking read loop needs: accumulate socket reads into a
+    /// buffer, then repeatedly call `next_adu` to drain every completed ADU (pipelined requests
+    /// included) while leaving a trailing partial ADU in `remaining` for the next read. A buffer
+    /// holding exactly one ADU (`data.len() == adu_length(data)`) is accepted and leaves
+    /// `remaining` empty.
+    ///
+    /// Returns [`ModbusError::NotEnoughData`] if `data` doesn't yet hold a complete ADU. Note that
+    /// for [`RtuModbus`], which has no length field, `adu_length` (and therefore this method) can
+    /// only succeed once `data` holds exactly one ADU, so it can't on its own carve a single frame
+    /// out of a buffer holding several pipelined RTU ADUs.
+    fn next_adu(data: &[u8]) -> Result<(&[u8], &[u8]), ModbusError> {
+        use ModbusError::NotEnoughData;
+
+        let length = Self::adu_length(data)?;
+
+        if data.len() < length {
+            return Err(NotEnoughData);
+        }
+
+        let (this_adu, remaining) = data.split_at(length);
+        Self::adu_check(this_adu)?;
+
+        Ok((this_adu, remaining))
+    }
+}
@@ -0,0 +1,224 @@
+use super::ModbusProtocol;
+use crate::ModbusError;
+
+/// RTU MODBUS protocol implementation
+///
+/// RTU MODBUS is typically used over serial lines. Unlike TCP MODBUS, it has no length field;
+/// instead, an ADU is framed as `[unit id][PDU][CRC lo][CRC hi]`, and the receiver is expected to
+/// rely on inter-frame silence to know where one ADU ends and the next begins. Since this crate
+/// only sees the bytes and not the timing, it takes the next best thing: a buffer is a complete
+/// ADU exactly when its trailing two bytes are a valid CRC-16 over everything before them.
+///
+/// This has an important consequence for [`ModbusProtocol::adu_length`]: unlike TCP MODBUS, RTU
+/// MODBUS has no field to read the length out of, so `adu_length` can only succeed once `data`
+/// holds a complete, validly-checksummed ADU. A buffer holding a truncated ADU (or the start of a
+/// longer one) is indistinguishable from one holding a corrupt ADU, so both report
+/// [`ModbusError::NotEnoughData`]. This is an inherent limitation of the RTU framing, not a defect
+/// in this implementation.
+///
+/// Visually, an RTU MODBUS ADU looks like this:
+///
+/// | Offset | Field |
+/// | --- | --- |
+/// | 0 | Unit ID |
+/// | 1 | Function Code |
+/// | 2... | Continuing PDU Data |
+/// | len-2 | CRC low byte |
+/// | len-1 | CRC high byte |
+pub struct RtuModbus;
+
+// Minimum ADU size: 1-byte unit ID, 1-byte function code, 2-byte CRC
+const MIN_ADU_LENGTH: usize = 4;
+
+/// RTU MODBUS header data
+#[derive(Debug, Clone)]
+pub struct RtuModbusHeader {
+    pub unit_id: u8,
+}
+
+impl RtuModbus {
+    /// Computes the standard MODBUS CRC-16 over `data`.
+    fn crc16(data: &[u8]) -> u16 {
+        let mut crc: u16 = 0xFFFF;
+
+        for &byte in data {
+            crc ^= byte as u16;
+
+            for _ in 0..8 {
+                if crc & 1 != 0 {
+                    crc = (crc >> 1) ^ 0xA001;
+                } else {
+                    crc >>= 1;
+                }
+            }
+        }
+
+        crc
+    }
+}
+
+impl ModbusProtocol for RtuModbus {
+    const ADU_MAX_LENGTH: usize = 256;
+
+    type Header = RtuModbusHeader;
+    type PduBody<'a> = &'a [u8];
+
+    /// RTU MODBUS has no length field, so this only succeeds once `data` holds a complete ADU with
+    /// a valid trailing CRC; see the type-level documentation for why.
+    fn adu_length(data: &[u8]) -> Result<usize, ModbusError> {
+        Self::adu_check(data)?;
+
+        Ok(data.len())
+    }
+
+    fn adu_header(data: &[u8]) -> Result<Self::Header, ModbusError> {
+        use ModbusError::NotEnoughData;
+
+        Ok(Self::Header {
+            unit_id: *data.first().ok_or(NotEnoughData)?,
+        })
+    }
+
+    fn adu_check(data: &[u8]) -> Result<(), ModbusError> {
+        use ModbusError::NotEnoughData;
+
+        if data.len() < MIN_ADU_LENGTH {
+            return Err(NotEnoughData);
+        }
+
+        let (payload, crc_bytes) = data.split_at(data.len() - 2);
+        let expected_crc = u16::from_le_bytes([crc_bytes[0], crc_bytes[1]]);
+
+        if Self::crc16(payload) == expected_crc {
+            Ok(())
+        } else {
+            Err(NotEnoughData)
+        }
+    }
+
+    fn pdu_body(data: &[u8]) -> Result<&[u8], ModbusError> {
+        Self::adu_check(data)?;
+
+        // We just checked that the CRC (and therefore the length) is correct, so this won't panic
+        Ok(&data[1..data.len() - 2])
+    }
+
+    fn build_adu(
+        header: &Self::Header,
+        pdu_body: &[u8],
+        out: &mut [u8],
+    ) -> Result<usize, ModbusError> {
+        let adu_length = 1 + pdu_body.len() + 2;
+        let out = out.get_mut(..adu_length).ok_or(ModbusError::NotEnoughData)?;
+
+        out[0] = header.unit_id;
+        out[1..1 + pdu_body.len()].copy_from_slice(pdu_body);
+
+        let crc = Self::crc16(&out[..1 + pdu_body.len()]);
+        out[1 + pdu_body.len()..].copy_from_slice(&crc.to_le_bytes());
+
+        Ok(adu_length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_adu_round_trips_through_adu_check_and_pdu_body() {
+        let header = RtuModbusHeader { unit_id: 0x11 };
+        let pdu_body = [0x03, 0x00, 0x00, 0x00, 0x02];
+        let mut buf = [0u8; RtuModbus::ADU_MAX_LENGTH];
+
+        let written = RtuModbus::build_adu(&header, &pdu_body, &mut buf).unwrap();
+        let adu = &buf[..written];
+
+        assert_eq!(written, 1 + pdu_body.len() + 2);
+        RtuModbus::adu_check(adu).unwrap();
+        assert_eq!(RtuModbus::pdu_body(adu).unwrap(), &pdu_body);
+        assert_eq!(RtuModbus::adu_header(adu).unwrap().unit_id, header.unit_id);
+    }
+
+    // Reference vector: a ReadHoldingRegisters request for unit 0x11, taken from the MODBUS over
+    // serial line specification's worked CRC example
+    const REFERENCE_PAYLOAD: [u8; 6] = [0x11, 0x03, 0x00, 0x6B, 0x00, 0x03];
+    const REFERENCE_CRC: [u8; 2] = [0x76, 0x87];
+
+    #[test]
+    fn crc16_matches_known_reference_vector() {
+        let crc = RtuModbus::crc16(&REFERENCE_PAYLOAD);
+        assert_eq!(crc, u16::from_le_bytes(REFERENCE_CRC));
+    }
+
+    #[test]
+    fn adu_check_accepts_a_valid_reference_adu() {
+        let mut adu = REFERENCE_PAYLOAD.to_vec();
+        adu.extend_from_slice(&REFERENCE_CRC);
+
+        assert_eq!(RtuModbus::adu_check(&adu), Ok(()));
+        assert_eq!(RtuModbus::pdu_body(&adu).unwrap(), &REFERENCE_PAYLOAD[1..]);
+    }
+
+    #[test]
+    fn adu_check_rejects_a_mismatched_crc() {
+        let mut adu = REFERENCE_PAYLOAD.to_vec();
+        adu.extend_from_slice(&REFERENCE_CRC);
+        let last = adu.len() - 1;
+        adu[last] ^= 0xFF; // corrupt the CRC high byte
+
+        assert_eq!(RtuModbus::adu_check(&adu), Err(ModbusError::NotEnoughData));
+        assert_eq!(
+            RtuModbus::pdu_body(&adu),
+            Err(ModbusError::NotEnoughData)
+        );
+    }
+
+    #[test]
+    fn adu_check_rejects_a_truncated_buffer() {
+        let mut adu = REFERENCE_PAYLOAD.to_vec();
+        adu.extend_from_slice(&REFERENCE_CRC);
+        adu.pop();
+
+        assert_eq!(RtuModbus::adu_check(&adu), Err(ModbusError::NotEnoughData));
+    }
+
+    #[test]
+    fn adu_check_rejects_a_buffer_shorter_than_the_minimum_adu() {
+        assert_eq!(
+            RtuModbus::adu_check(&[0x11, 0x03, 0x00]),
+            Err(ModbusError::NotEnoughData)
+        );
+    }
+
+    #[test]
+    fn next_adu_cannot_split_two_concatenated_rtu_adus() {
+        // RTU has no length field, so adu_length only succeeds once the whole buffer is one
+        // validly-checksummed ADU; a buffer holding two concatenated ADUs isn't one, so next_adu
+        // can't carve the first one off. This is the documented, inherent limitation of RTU
+        // framing, not something callers can work around with this primitive alone.
+        let header = RtuModbusHeader { unit_id: 0x11 };
+        let first_pdu = [0x03, 0x00, 0x00, 0x00, 0x02];
+        let second_pdu = [0x10, 0x00, 0x01];
+
+        let mut first_buf = [0u8; RtuModbus::ADU_MAX_LENGTH];
+        let first_len = RtuModbus::build_adu(&header, &first_pdu, &mut first_buf).unwrap();
+
+        let mut second_buf = [0u8; RtuModbus::ADU_MAX_LENGTH];
+        let second_len = RtuModbus::build_adu(&header, &second_pdu, &mut second_buf).unwrap();
+
+        let mut combined = Vec::new();
+        combined.extend_from_slice(&first_buf[..first_len]);
+        combined.extend_from_slice(&second_buf[..second_len]);
+
+        assert_eq!(
+            RtuModbus::next_adu(&combined),
+            Err(ModbusError::NotEnoughData)
+        );
+
+        // Given just the first ADU on its own, it works fine
+        let (adu, rest) = RtuModbus::next_adu(&first_buf[..first_len]).unwrap();
+        assert_eq!(adu, &first_buf[..first_len]);
+        assert!(rest.is_empty());
+    }
+}
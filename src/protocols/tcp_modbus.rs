@@ -106,15 +106,44 @@ impl TcpModbus {
     }
 }
 
+// Smallest and largest values the length field can hold for a real ADU: 1 byte of unit ID plus at
+// least a 1-byte function code, up to the largest PDU MODBUS TCP allows
+const MIN_LENGTH_FIELD: u16 = 2;
+const MAX_LENGTH_FIELD: u16 = 254;
+
+impl TcpModbus {
+    /// Cheaply checks whether `data` plausibly begins with a MODBUS TCP ADU, without fully
+    /// parsing or validating it.
+    ///
+    /// This only looks at the MBAP header: it requires at least 8 bytes (the 7-byte MBAP plus one
+    /// PDU byte), a protocol ID of 0, and a length field within the range a real ADU can have. A
+    /// stream reader can use this to resynchronize on a byte stream and reject obvious garbage
+    /// before committing to a full parse via [`ModbusProtocol::adu_check`].
+    pub fn is_like_modbus_tcp(data: &[u8]) -> bool {
+        if data.len() < MBAP_LENGTH + 1 {
+            return false;
+        }
+
+        let Some(0) = Self::protocol_id(data) else {
+            return false;
+        };
+
+        matches!(Self::length(data), Some(MIN_LENGTH_FIELD..=MAX_LENGTH_FIELD))
+    }
+}
+
 impl ModbusProtocol for TcpModbus {
     const ADU_MAX_LENGTH: usize = 260;
 
     type Header = TcpModbusHeader;
+    type PduBody<'a> = &'a [u8];
 
     fn adu_length(data: &[u8]) -> Result<usize, ModbusError> {
         match Self::length(data) {
             None => Err(ModbusError::NotEnoughData),
-            Some(v) => Ok(v as usize + MBAP_LENGTH),
+            // The length field already includes the unit ID, so adding MBAP_LENGTH here would
+            // double-count it; EXCLUDED_LENGTH is the field's complement within the MBAP.
+            Some(v) => Ok(v as usize + EXCLUDED_LENGTH),
         }
     }
 
@@ -136,7 +165,7 @@ impl ModbusProtocol for TcpModbus {
 
         let length = Self::adu_length(data)?;
 
-        if data.len() > length {
+        if data.len() >= length {
             Ok(())
         } else {
             Err(NotEnoughData)
@@ -150,4 +179,158 @@ impl ModbusProtocol for TcpModbus {
         // won't panic
         Ok(&data[MBAP_LENGTH..])
     }
+
+    fn build_adu(
+        header: &Self::Header,
+        pdu_body: &[u8],
+        out: &mut [u8],
+    ) -> Result<usize, ModbusError> {
+        // The length field covers everything after itself: the unit ID and the PDU. It's a u16, so
+        // reject PDUs that don't fit rather than silently wrapping into a corrupt length field.
+        let length: u16 = (pdu_body.len() + 1)
+            .try_into()
+            .map_err(|_| ModbusError::NotEnoughData)?;
+
+        let adu_length = MBAP_LENGTH + pdu_body.len();
+        let out = out.get_mut(..adu_length).ok_or(ModbusError::NotEnoughData)?;
+
+        out[0..2].copy_from_slice(&header.transaction_id.to_be_bytes());
+        out[2..4].copy_from_slice(&0u16.to_be_bytes());
+        out[4..6].copy_from_slice(&length.to_be_bytes());
+        out[6] = header.unit_id;
+        out[MBAP_LENGTH..].copy_from_slice(pdu_body);
+
+        Ok(adu_length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_adu_round_trips_through_adu_check_and_pdu_body() {
+        let header = TcpModbusHeader {
+            transaction_id: 0x1234,
+            protocol_id: 0,
+            length: 0,
+            unit_id: 0x01,
+        };
+        let pdu_body = [0x03, 0x00, 0x00, 0x00, 0x02];
+        let mut buf = [0u8; TcpModbus::ADU_MAX_LENGTH];
+
+        let written = TcpModbus::build_adu(&header, &pdu_body, &mut buf).unwrap();
+        let adu = &buf[..written];
+
+        assert_eq!(written, MBAP_LENGTH + pdu_body.len());
+        TcpModbus::adu_check(adu).unwrap();
+        assert_eq!(TcpModbus::pdu_body(adu).unwrap(), &pdu_body);
+
+        let parsed_header = TcpModbus::adu_header(adu).unwrap();
+        assert_eq!(parsed_header.transaction_id, header.transaction_id);
+        assert_eq!(parsed_header.unit_id, header.unit_id);
+    }
+
+    #[test]
+    fn next_adu_splits_two_concatenated_adus_without_consuming_extra_bytes() {
+        let header = TcpModbusHeader {
+            transaction_id: 1,
+            protocol_id: 0,
+            length: 0,
+            unit_id: 0x01,
+        };
+        let first_pdu = [0x03, 0x00, 0x00, 0x00, 0x02];
+        let second_pdu = [0x10, 0x00, 0x01];
+
+        let mut first_buf = [0u8; TcpModbus::ADU_MAX_LENGTH];
+        let first_len = TcpModbus::build_adu(&header, &first_pdu, &mut first_buf).unwrap();
+
+        let mut second_buf = [0u8; TcpModbus::ADU_MAX_LENGTH];
+        let second_len = TcpModbus::build_adu(&header, &second_pdu, &mut second_buf).unwrap();
+
+        let mut combined = Vec::new();
+        combined.extend_from_slice(&first_buf[..first_len]);
+        combined.extend_from_slice(&second_buf[..second_len]);
+
+        let (first_adu, rest) = TcpModbus::next_adu(&combined).unwrap();
+        assert_eq!(first_adu, &first_buf[..first_len]);
+        assert_eq!(rest, &second_buf[..second_len]);
+
+        let (second_adu, rest) = TcpModbus::next_adu(rest).unwrap();
+        assert_eq!(second_adu, &second_buf[..second_len]);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn is_like_modbus_tcp_accepts_a_valid_header() {
+        let header = TcpModbusHeader {
+            transaction_id: 1,
+            protocol_id: 0,
+            length: 0,
+            unit_id: 0x01,
+        };
+        let pdu_body = [0x03, 0x00, 0x00, 0x00, 0x02];
+        let mut buf = [0u8; TcpModbus::ADU_MAX_LENGTH];
+        let written = TcpModbus::build_adu(&header, &pdu_body, &mut buf).unwrap();
+
+        assert!(TcpModbus::is_like_modbus_tcp(&buf[..written]));
+    }
+
+    #[test]
+    fn is_like_modbus_tcp_rejects_a_buffer_shorter_than_the_mbap_plus_one_byte() {
+        let frame = [0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x01, 0x03];
+        assert!(!TcpModbus::is_like_modbus_tcp(&frame[..MBAP_LENGTH]));
+    }
+
+    #[test]
+    fn is_like_modbus_tcp_rejects_a_nonzero_protocol_id() {
+        let frame = [0x00, 0x01, 0x00, 0x01, 0x00, 0x02, 0x01, 0x03];
+        assert!(!TcpModbus::is_like_modbus_tcp(&frame));
+    }
+
+    #[test]
+    fn is_like_modbus_tcp_rejects_a_length_field_below_the_valid_range() {
+        let frame = [0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x01, 0x03];
+        assert!(!TcpModbus::is_like_modbus_tcp(&frame));
+    }
+
+    #[test]
+    fn is_like_modbus_tcp_rejects_a_length_field_above_the_valid_range() {
+        let frame = [0x00, 0x01, 0x00, 0x00, 0x00, 0xFF, 0x01, 0x03];
+        assert!(!TcpModbus::is_like_modbus_tcp(&frame));
+    }
+
+    #[test]
+    fn build_adu_rejects_a_pdu_that_overflows_the_u16_length_field() {
+        let header = TcpModbusHeader {
+            transaction_id: 1,
+            protocol_id: 0,
+            length: 0,
+            unit_id: 0x01,
+        };
+        // pdu_body.len() + 1 == 65536, which doesn't fit in the u16 length field
+        let pdu_body = vec![0u8; u16::MAX as usize];
+        let mut out = vec![0u8; MBAP_LENGTH + pdu_body.len()];
+
+        assert_eq!(
+            TcpModbus::build_adu(&header, &pdu_body, &mut out),
+            Err(ModbusError::NotEnoughData)
+        );
+    }
+
+    #[test]
+    fn build_adu_accepts_the_largest_pdu_that_fits_the_length_field() {
+        let header = TcpModbusHeader {
+            transaction_id: 1,
+            protocol_id: 0,
+            length: 0,
+            unit_id: 0x01,
+        };
+        // pdu_body.len() + 1 == 65535, the largest value the length field can hold
+        let pdu_body = vec![0u8; u16::MAX as usize - 1];
+        let mut out = vec![0u8; MBAP_LENGTH + pdu_body.len()];
+
+        let written = TcpModbus::build_adu(&header, &pdu_body, &mut out).unwrap();
+        assert_eq!(written, MBAP_LENGTH + pdu_body.len());
+    }
 }